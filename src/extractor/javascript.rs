@@ -0,0 +1,179 @@
+//! A lightweight JavaScript/TypeScript backend: a line-oriented scan for
+//! `class`/`function` declarations and class methods, rather than a full
+//! parser, into the same [`Symbol`] model the Rust backend produces.
+
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+use regex::Regex;
+
+use super::SymbolExtractor;
+use crate::model::{FileSymbols, Symbol, SymbolKind, Visibility};
+
+pub struct JavaScriptExtractor;
+
+impl SymbolExtractor for JavaScriptExtractor {
+    fn extract(&self, path: &Path) -> Result<FileSymbols> {
+        let source = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        Ok(FileSymbols {
+            path: path.to_path_buf(),
+            symbols: extract_source(&source),
+        })
+    }
+}
+
+fn class_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^(?:export\s+)?(?:default\s+)?class\s+(\w+)").unwrap())
+}
+
+fn function_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^(?:export\s+)?(?:default\s+)?(?:async\s+)?function\s*\*?\s+(\w+)\s*\(")
+            .unwrap()
+    })
+}
+
+fn method_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^(?:static\s+)?(?:async\s+)?(?:get\s+|set\s+)?(#?[\w$]+)\s*\(([^)]*)\)\s*\{")
+            .unwrap()
+    })
+}
+
+/// Keywords that look like a method call followed by a block (`if (...) {`,
+/// `for (...) {`, ...) but aren't declarations.
+const NOT_A_METHOD: &[&str] = &["if", "for", "while", "switch", "catch"];
+
+/// Extract top-level classes (with their methods as children) and
+/// top-level functions, using any leading `//` or JSDoc `/** ... */`
+/// comment as doc text.
+fn extract_source(source: &str) -> Vec<Symbol> {
+    let mut symbols = Vec::new();
+    let mut pending_doc: Vec<String> = Vec::new();
+    let mut in_block_comment = false;
+
+    let mut depth: i32 = 0;
+    // (index into `symbols`, brace depth when the class body opened)
+    let mut class_stack: Vec<(usize, i32)> = Vec::new();
+
+    for (idx, raw_line) in source.lines().enumerate() {
+        let line = raw_line.trim();
+        let line_no = idx + 1;
+
+        if line.is_empty() {
+            // A blank line breaks the association between a comment and
+            // whatever declaration follows it.
+            pending_doc.clear();
+            continue;
+        }
+
+        if in_block_comment {
+            push_doc_line(&mut pending_doc, strip_comment_marker(line));
+            if line.ends_with("*/") {
+                in_block_comment = false;
+            }
+            continue;
+        }
+        if line.starts_with("/*") {
+            push_doc_line(&mut pending_doc, strip_comment_marker(line));
+            if !line.ends_with("*/") {
+                in_block_comment = true;
+            }
+            continue;
+        }
+        if line.starts_with("//") {
+            pending_doc.push(line.trim_start_matches('/').trim().to_string());
+            continue;
+        }
+
+        let doc = (!pending_doc.is_empty()).then(|| pending_doc.join("\n"));
+        pending_doc.clear();
+
+        if let Some(caps) = class_re().captures(line).filter(|_| depth == 0) {
+            symbols.push(Symbol {
+                name: caps[1].to_string(),
+                kind: SymbolKind::Struct,
+                visibility: Visibility::Public,
+                doc,
+                line: line_no,
+                end_line: line_no, // corrected once the class body closes
+                signature: line.to_string(),
+                generics: Vec::new(),
+                children: Vec::new(),
+            });
+            class_stack.push((symbols.len() - 1, depth));
+        } else if let Some(caps) = function_re().captures(line).filter(|_| depth == 0) {
+            symbols.push(Symbol {
+                name: caps[1].to_string(),
+                kind: SymbolKind::Function,
+                visibility: Visibility::Public,
+                doc,
+                line: line_no,
+                end_line: line_no,
+                signature: line.to_string(),
+                generics: Vec::new(),
+                children: Vec::new(),
+            });
+        } else if let Some(&(class_idx, _)) = class_stack.last() {
+            if let Some(caps) = method_re().captures(line) {
+                let name = &caps[1];
+                if !NOT_A_METHOD.contains(&name) {
+                    symbols[class_idx].children.push(Symbol {
+                        name: name.to_string(),
+                        kind: SymbolKind::Method { has_default: true },
+                        visibility: if name.starts_with('#') {
+                            Visibility::Private
+                        } else {
+                            Visibility::Public
+                        },
+                        doc,
+                        line: line_no,
+                        end_line: line_no,
+                        signature: line.to_string(),
+                        generics: Vec::new(),
+                        children: Vec::new(),
+                    });
+                }
+            }
+        }
+
+        depth += line.matches('{').count() as i32;
+        depth -= line.matches('}').count() as i32;
+
+        while let Some(&(class_idx, start_depth)) = class_stack.last() {
+            if depth <= start_depth {
+                symbols[class_idx].end_line = line_no;
+                class_stack.pop();
+            } else {
+                break;
+            }
+        }
+    }
+
+    symbols
+}
+
+/// Push a stripped comment line, skipping the blank marker-only lines
+/// (`/**`, `*/`) that JSDoc blocks open and close with.
+fn push_doc_line(pending_doc: &mut Vec<String>, line: String) {
+    if !line.is_empty() {
+        pending_doc.push(line);
+    }
+}
+
+/// Strip `/**`, `/*`, `*/`, and leading `*` JSDoc markers off a comment line.
+fn strip_comment_marker(line: &str) -> String {
+    line.trim_start_matches("/**")
+        .trim_start_matches("/*")
+        .trim_end_matches("*/")
+        .trim()
+        .trim_start_matches('*')
+        .trim()
+        .to_string()
+}
@@ -0,0 +1,21 @@
+//! Symbol extraction, behind a language-agnostic trait so new languages can
+//! be added without touching the walking, rendering, or storage pipeline.
+
+mod javascript;
+mod registry;
+mod rust;
+
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::model::FileSymbols;
+
+pub use javascript::JavaScriptExtractor;
+pub use registry::ExtractorRegistry;
+pub use rust::{extract_file, RustExtractor};
+
+/// Extracts the [`FileSymbols`] for a single source file in one language.
+pub trait SymbolExtractor {
+    fn extract(&self, path: &Path) -> Result<FileSymbols>;
+}
@@ -0,0 +1,70 @@
+//! Looks up the right [`SymbolExtractor`] for a file by its extension, so
+//! the ingestion pipeline can walk a directory of mixed-language source
+//! without knowing ahead of time which languages it contains.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{bail, Result};
+
+use super::{JavaScriptExtractor, RustExtractor, SymbolExtractor};
+use crate::model::FileSymbols;
+
+pub struct ExtractorRegistry {
+    by_extension: HashMap<&'static str, Box<dyn SymbolExtractor + Send + Sync>>,
+}
+
+impl ExtractorRegistry {
+    /// An empty registry with no languages registered.
+    pub fn new() -> Self {
+        Self {
+            by_extension: HashMap::new(),
+        }
+    }
+
+    /// A registry with the backends this crate ships: Rust (`.rs`) and
+    /// JavaScript/TypeScript (`.js`, `.jsx`, `.ts`, `.tsx`).
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        registry.register("rs", RustExtractor);
+        registry.register("js", JavaScriptExtractor);
+        registry.register("jsx", JavaScriptExtractor);
+        registry.register("ts", JavaScriptExtractor);
+        registry.register("tsx", JavaScriptExtractor);
+        registry
+    }
+
+    /// Register `extractor` as the backend for files with `extension`
+    /// (without the leading dot), replacing any previous registration.
+    pub fn register(
+        &mut self,
+        extension: &'static str,
+        extractor: impl SymbolExtractor + Send + Sync + 'static,
+    ) {
+        self.by_extension.insert(extension, Box::new(extractor));
+    }
+
+    /// Every file extension with a registered extractor.
+    pub fn extensions(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.by_extension.keys().copied()
+    }
+
+    /// Extract `path` with whichever backend is registered for its
+    /// extension.
+    pub fn extract_file(&self, path: &Path) -> Result<FileSymbols> {
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or_default();
+        match self.by_extension.get(extension) {
+            Some(extractor) => extractor.extract(path),
+            None => bail!(
+                "no extractor registered for extension `{extension}` ({})",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Default for ExtractorRegistry {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
@@ -0,0 +1,343 @@
+//! Extracts [`Symbol`]s from Rust source files using `syn`.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use syn::spanned::Spanned;
+
+use super::SymbolExtractor;
+use crate::model::{format_generics, FileSymbols, GenericParam, Symbol, SymbolKind, Visibility};
+
+/// The Rust backend, implemented with `syn`. Also used directly by
+/// [`crate::workspace`], which needs the concrete `extract_file` function
+/// to parse `mod`-declared files as it walks a crate's module tree.
+pub struct RustExtractor;
+
+impl SymbolExtractor for RustExtractor {
+    fn extract(&self, path: &Path) -> Result<FileSymbols> {
+        extract_file(path)
+    }
+}
+
+/// Parse a single Rust source file and collect its top-level items: structs,
+/// functions, impl blocks (with their methods as children), traits, enums,
+/// type aliases, consts/statics, and `macro_rules!` definitions.
+pub fn extract_file(path: &Path) -> Result<FileSymbols> {
+    let source = fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let file = syn::parse_file(&source)
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+
+    let symbols = file.items.iter().filter_map(extract_item).collect();
+
+    Ok(FileSymbols {
+        path: path.to_path_buf(),
+        symbols,
+    })
+}
+
+fn extract_item(item: &syn::Item) -> Option<Symbol> {
+    match item {
+        syn::Item::Struct(s) => {
+            let generics = extract_generics(&s.generics);
+            Some(Symbol {
+                name: s.ident.to_string(),
+                kind: SymbolKind::Struct,
+                visibility: visibility_of(&s.vis),
+                doc: doc_comment(&s.attrs),
+                line: s.span().start().line,
+                end_line: s.span().end().line,
+                signature: format!(
+                    "{}struct {}{}",
+                    vis_prefix(&s.vis),
+                    s.ident,
+                    format_generics(&generics)
+                ),
+                generics,
+                children: Vec::new(),
+            })
+        }
+        syn::Item::Fn(f) => {
+            let generics = extract_generics(&f.sig.generics);
+            Some(Symbol {
+                name: f.sig.ident.to_string(),
+                kind: SymbolKind::Function,
+                visibility: visibility_of(&f.vis),
+                doc: doc_comment(&f.attrs),
+                line: f.span().start().line,
+                end_line: f.span().end().line,
+                signature: fn_signature(&f.vis, &f.sig),
+                generics,
+                children: Vec::new(),
+            })
+        }
+        syn::Item::Impl(i) => {
+            let target = type_name(&i.self_ty);
+            let generics = extract_generics(&i.generics);
+            let methods = i.items.iter().filter_map(extract_impl_item).collect();
+            Some(Symbol {
+                name: target.clone(),
+                signature: format!("impl{} {}", format_generics(&generics), target),
+                kind: SymbolKind::Impl { target },
+                visibility: Visibility::Private,
+                doc: doc_comment(&i.attrs),
+                line: i.span().start().line,
+                end_line: i.span().end().line,
+                generics,
+                children: methods,
+            })
+        }
+        syn::Item::Trait(t) => {
+            let generics = extract_generics(&t.generics);
+            let methods = t.items.iter().filter_map(extract_trait_item).collect();
+            Some(Symbol {
+                name: t.ident.to_string(),
+                signature: format!(
+                    "{}trait {}{}",
+                    vis_prefix(&t.vis),
+                    t.ident,
+                    format_generics(&generics)
+                ),
+                kind: SymbolKind::Trait,
+                visibility: visibility_of(&t.vis),
+                doc: doc_comment(&t.attrs),
+                line: t.span().start().line,
+                end_line: t.span().end().line,
+                generics,
+                children: methods,
+            })
+        }
+        syn::Item::Enum(e) => {
+            let generics = extract_generics(&e.generics);
+            let variants = e
+                .variants
+                .iter()
+                .map(|v| Symbol {
+                    name: v.ident.to_string(),
+                    signature: v.ident.to_string(),
+                    kind: SymbolKind::Variant,
+                    visibility: Visibility::Public,
+                    doc: doc_comment(&v.attrs),
+                    line: v.span().start().line,
+                    end_line: v.span().end().line,
+                    generics: Vec::new(),
+                    children: Vec::new(),
+                })
+                .collect();
+            Some(Symbol {
+                name: e.ident.to_string(),
+                signature: format!(
+                    "{}enum {}{}",
+                    vis_prefix(&e.vis),
+                    e.ident,
+                    format_generics(&generics)
+                ),
+                kind: SymbolKind::Enum,
+                visibility: visibility_of(&e.vis),
+                doc: doc_comment(&e.attrs),
+                line: e.span().start().line,
+                end_line: e.span().end().line,
+                generics,
+                children: variants,
+            })
+        }
+        syn::Item::Type(t) => {
+            let generics = extract_generics(&t.generics);
+            let ty = &t.ty;
+            Some(Symbol {
+                name: t.ident.to_string(),
+                signature: format!(
+                    "{}type {}{} = {}",
+                    vis_prefix(&t.vis),
+                    t.ident,
+                    format_generics(&generics),
+                    quote::quote!(#ty)
+                ),
+                kind: SymbolKind::TypeAlias,
+                visibility: visibility_of(&t.vis),
+                doc: doc_comment(&t.attrs),
+                line: t.span().start().line,
+                end_line: t.span().end().line,
+                generics,
+                children: Vec::new(),
+            })
+        }
+        syn::Item::Const(c) => {
+            let ty = &c.ty;
+            Some(Symbol {
+                name: c.ident.to_string(),
+                signature: format!("{}const {}: {}", vis_prefix(&c.vis), c.ident, quote::quote!(#ty)),
+                kind: SymbolKind::Const,
+                visibility: visibility_of(&c.vis),
+                doc: doc_comment(&c.attrs),
+                line: c.span().start().line,
+                end_line: c.span().end().line,
+                generics: Vec::new(),
+                children: Vec::new(),
+            })
+        }
+        syn::Item::Static(s) => {
+            let ty = &s.ty;
+            Some(Symbol {
+                name: s.ident.to_string(),
+                signature: format!("{}static {}: {}", vis_prefix(&s.vis), s.ident, quote::quote!(#ty)),
+                kind: SymbolKind::Static,
+                visibility: visibility_of(&s.vis),
+                doc: doc_comment(&s.attrs),
+                line: s.span().start().line,
+                end_line: s.span().end().line,
+                generics: Vec::new(),
+                children: Vec::new(),
+            })
+        }
+        syn::Item::Macro(m) if m.mac.path.is_ident("macro_rules") => {
+            let name = m.ident.as_ref()?.to_string();
+            Some(Symbol {
+                signature: format!("macro_rules! {name}"),
+                name,
+                kind: SymbolKind::Macro,
+                visibility: Visibility::Private,
+                doc: doc_comment(&m.attrs),
+                line: m.span().start().line,
+                end_line: m.span().end().line,
+                generics: Vec::new(),
+                children: Vec::new(),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn extract_impl_item(item: &syn::ImplItem) -> Option<Symbol> {
+    match item {
+        syn::ImplItem::Fn(f) => {
+            let generics = extract_generics(&f.sig.generics);
+            Some(Symbol {
+                name: f.sig.ident.to_string(),
+                signature: fn_signature(&f.vis, &f.sig),
+                kind: SymbolKind::Method { has_default: true },
+                visibility: visibility_of(&f.vis),
+                doc: doc_comment(&f.attrs),
+                line: f.span().start().line,
+                end_line: f.span().end().line,
+                generics,
+                children: Vec::new(),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn extract_trait_item(item: &syn::TraitItem) -> Option<Symbol> {
+    match item {
+        syn::TraitItem::Fn(f) => {
+            let generics = extract_generics(&f.sig.generics);
+            Some(Symbol {
+                name: f.sig.ident.to_string(),
+                signature: fn_signature(&syn::Visibility::Inherited, &f.sig),
+                kind: SymbolKind::Method {
+                    has_default: f.default.is_some(),
+                },
+                visibility: Visibility::Public,
+                doc: doc_comment(&f.attrs),
+                line: f.span().start().line,
+                end_line: f.span().end().line,
+                generics,
+                children: Vec::new(),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn visibility_of(vis: &syn::Visibility) -> Visibility {
+    match vis {
+        syn::Visibility::Public(_) => Visibility::Public,
+        _ => Visibility::Private,
+    }
+}
+
+fn vis_prefix(vis: &syn::Visibility) -> &'static str {
+    match vis {
+        syn::Visibility::Public(_) => "pub ",
+        _ => "",
+    }
+}
+
+/// Reconstruct a function's declaration (visibility, name, generics,
+/// parameters, return type) from its parsed signature.
+fn fn_signature(vis: &syn::Visibility, sig: &syn::Signature) -> String {
+    format!("{}{}", vis_prefix(vis), quote::quote!(#sig))
+}
+
+fn type_name(ty: &syn::Type) -> String {
+    match ty {
+        syn::Type::Path(p) => p
+            .path
+            .segments
+            .last()
+            .map(|s| s.ident.to_string())
+            .unwrap_or_else(|| quote::quote!(#ty).to_string()),
+        _ => quote::quote!(#ty).to_string(),
+    }
+}
+
+/// Collect a `<...>` parameter list's generic type/lifetime/const params,
+/// including any trait or lifetime bounds written inline on them.
+fn extract_generics(generics: &syn::Generics) -> Vec<GenericParam> {
+    generics
+        .params
+        .iter()
+        .map(|param| match param {
+            syn::GenericParam::Type(t) => GenericParam {
+                name: t.ident.to_string(),
+                bounds: t
+                    .bounds
+                    .iter()
+                    .map(|b| quote::quote!(#b).to_string())
+                    .collect(),
+            },
+            syn::GenericParam::Lifetime(l) => GenericParam {
+                name: l.lifetime.to_string(),
+                bounds: l
+                    .bounds
+                    .iter()
+                    .map(|b| quote::quote!(#b).to_string())
+                    .collect(),
+            },
+            syn::GenericParam::Const(c) => GenericParam {
+                name: c.ident.to_string(),
+                bounds: Vec::new(),
+            },
+        })
+        .collect()
+}
+
+/// Join consecutive `///` doc attributes into a single block of text.
+fn doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter_map(|attr| {
+            if !attr.path().is_ident("doc") {
+                return None;
+            }
+            let syn::Meta::NameValue(nv) = &attr.meta else {
+                return None;
+            };
+            let syn::Expr::Lit(expr_lit) = &nv.value else {
+                return None;
+            };
+            let syn::Lit::Str(s) = &expr_lit.lit else {
+                return None;
+            };
+            Some(s.value().trim().to_string())
+        })
+        .collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
@@ -0,0 +1,5 @@
+pub mod extractor;
+pub mod model;
+pub mod render;
+pub mod storage;
+pub mod workspace;
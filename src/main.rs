@@ -0,0 +1,164 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use walkdir::WalkDir;
+
+use code_ingest::extractor::ExtractorRegistry;
+use code_ingest::model::FileSymbols;
+use code_ingest::render::render_codebook;
+use code_ingest::storage::{Storage, StoredSymbol};
+use code_ingest::workspace::ingest_workspace;
+
+/// Ingest Rust source files and render their symbols as a single
+/// navigable Markdown code book.
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Walk files or directories directly and render a code book.
+    Files {
+        /// Files or directories to ingest.
+        paths: Vec<PathBuf>,
+
+        /// Write the code book here instead of stdout.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Ingest a whole crate or workspace via `cargo metadata`, printing the
+    /// fully-qualified name of every symbol found.
+    Workspace {
+        /// Path to the `Cargo.toml` to inspect (defaults to the current directory's).
+        #[arg(long)]
+        manifest_path: Option<PathBuf>,
+    },
+    /// Walk files or directories and persist their symbols to a SQLite
+    /// database, so they can be queried later without re-parsing.
+    Store {
+        /// Files or directories to ingest.
+        paths: Vec<PathBuf>,
+
+        /// SQLite database file to write to (created if missing).
+        #[arg(long)]
+        db: PathBuf,
+    },
+    /// Query symbols previously persisted with `store`.
+    Query {
+        /// SQLite database file to read from.
+        #[arg(long)]
+        db: PathBuf,
+
+        #[command(subcommand)]
+        what: QueryCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum QueryCommand {
+    /// Every `pub` function or method.
+    PublicFunctions,
+    /// Every symbol extracted from a given file path.
+    File {
+        /// File path as it was recorded by `store` (see the `path` column of the `files` table).
+        path: String,
+    },
+    /// Full-text search over doc comments.
+    SearchDocs {
+        /// Substring to search for.
+        text: String,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Files { paths, output } => run_files(&paths, output.as_deref()),
+        Command::Workspace { manifest_path } => run_workspace(manifest_path.as_deref()),
+        Command::Store { paths, db } => run_store(&paths, &db).await,
+        Command::Query { db, what } => run_query(&db, what).await,
+    }
+}
+
+/// Walk `paths`, extracting every file whose extension has a registered
+/// [`SymbolExtractor`](code_ingest::extractor::SymbolExtractor), regardless
+/// of which language it's written in.
+fn walk_source_files(paths: &[PathBuf], registry: &ExtractorRegistry) -> Result<Vec<FileSymbols>> {
+    let mut files = Vec::new();
+    for path in paths {
+        for entry in WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
+            let entry_path = entry.path();
+            let is_registered = entry_path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| registry.extensions().any(|registered| registered == ext));
+            if is_registered {
+                files.push(registry.extract_file(entry_path)?);
+            }
+        }
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+fn run_files(paths: &[PathBuf], output: Option<&std::path::Path>) -> Result<()> {
+    let files = walk_source_files(paths, &ExtractorRegistry::with_defaults())?;
+
+    let codebook = render_codebook(&files);
+    match output {
+        Some(path) => std::fs::write(path, codebook)?,
+        None => println!("{codebook}"),
+    }
+
+    Ok(())
+}
+
+fn run_workspace(manifest_path: Option<&std::path::Path>) -> Result<()> {
+    let modules = ingest_workspace(manifest_path)?;
+
+    let mut names: Vec<String> = modules.iter().flat_map(|m| m.qualified_names()).collect();
+    names.sort();
+    for name in names {
+        println!("{name}");
+    }
+
+    Ok(())
+}
+
+async fn run_store(paths: &[PathBuf], db: &std::path::Path) -> Result<()> {
+    let files = walk_source_files(paths, &ExtractorRegistry::with_defaults())?;
+    let storage = Storage::open(db).await?;
+    for file in &files {
+        storage.insert_file(file).await?;
+    }
+    println!("stored {} file(s) in {}", files.len(), db.display());
+    Ok(())
+}
+
+async fn run_query(db: &std::path::Path, what: QueryCommand) -> Result<()> {
+    let storage = Storage::open(db).await?;
+    let symbols = match what {
+        QueryCommand::PublicFunctions => storage.public_functions().await?,
+        QueryCommand::File { path } => storage.symbols_in_file(&path).await?,
+        QueryCommand::SearchDocs { text } => storage.search_docs(&text).await?,
+    };
+    print_symbols(&symbols);
+    Ok(())
+}
+
+fn print_symbols(symbols: &[StoredSymbol]) {
+    for symbol in symbols {
+        println!(
+            "{}:{}-{}  {}",
+            symbol.file_path, symbol.start_line, symbol.end_line, symbol.signature
+        );
+        if let Some(doc) = &symbol.doc {
+            println!("    {doc}");
+        }
+    }
+}
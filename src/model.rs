@@ -0,0 +1,117 @@
+//! Common symbol model shared by the extractor, the renderer, and storage.
+
+use std::path::PathBuf;
+
+/// Visibility of an extracted item, as written in the source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    Public,
+    Private,
+}
+
+impl Visibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::Public => "public",
+            Visibility::Private => "private",
+        }
+    }
+}
+
+/// A generic parameter, e.g. the `T: Clone` in `fn foo<T: Clone>(...)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenericParam {
+    pub name: String,
+    /// Trait/lifetime bounds written inline on the parameter, as source text.
+    pub bounds: Vec<String>,
+}
+
+/// Render `<T: Clone, 'a>`-style generics, or an empty string when there are
+/// none.
+pub fn format_generics(generics: &[GenericParam]) -> String {
+    if generics.is_empty() {
+        return String::new();
+    }
+    let params: Vec<String> = generics
+        .iter()
+        .map(|g| {
+            if g.bounds.is_empty() {
+                g.name.clone()
+            } else {
+                format!("{}: {}", g.name, g.bounds.join(" + "))
+            }
+        })
+        .collect();
+    format!("<{}>", params.join(", "))
+}
+
+/// The kind of item a [`Symbol`] represents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SymbolKind {
+    Struct,
+    Function,
+    /// An `impl` block for `target`, grouping its methods as children.
+    Impl { target: String },
+    /// A method defined inside an `impl` or `trait` block. `has_default`
+    /// is `false` for a trait method with no body, i.e. a required method.
+    Method { has_default: bool },
+    Trait,
+    Enum,
+    /// A single variant nested under an [`SymbolKind::Enum`] symbol.
+    Variant,
+    TypeAlias,
+    Const,
+    Static,
+    /// A `macro_rules!` definition.
+    Macro,
+}
+
+impl SymbolKind {
+    /// Short, stable label used as the `kind` column in storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SymbolKind::Struct => "struct",
+            SymbolKind::Function => "function",
+            SymbolKind::Impl { .. } => "impl",
+            SymbolKind::Method { .. } => "method",
+            SymbolKind::Trait => "trait",
+            SymbolKind::Enum => "enum",
+            SymbolKind::Variant => "variant",
+            SymbolKind::TypeAlias => "type-alias",
+            SymbolKind::Const => "const",
+            SymbolKind::Static => "static",
+            SymbolKind::Macro => "macro",
+        }
+    }
+}
+
+/// A single extracted item of any [`SymbolKind`] (a JavaScript class is
+/// recorded as a struct), with nested items such as methods or enum
+/// variants as its children.
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub visibility: Visibility,
+    /// Doc comment text (Rust `///` lines, or the JavaScript `//` or JSDoc
+    /// comment right above the declaration), joined with newlines, if present.
+    pub doc: Option<String>,
+    /// 1-based source line the item starts on.
+    pub line: usize,
+    /// 1-based source line the item ends on.
+    pub end_line: usize,
+    /// The item's declaration. For Rust, reconstructed from the parsed AST
+    /// (name, generics, parameters, return type) - not verbatim source text;
+    /// for JavaScript, the declaration's trimmed source line as written.
+    pub signature: String,
+    /// Generic parameters declared on this item, e.g. `<T: Clone, 'a>`.
+    pub generics: Vec<GenericParam>,
+    pub children: Vec<Symbol>,
+}
+
+/// All symbols extracted from a single source file.
+#[derive(Debug, Clone)]
+pub struct FileSymbols {
+    pub path: PathBuf,
+    pub symbols: Vec<Symbol>,
+}
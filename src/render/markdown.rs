@@ -0,0 +1,71 @@
+//! Renders extracted symbols as a single aggregated Markdown "code book",
+//! with a table of contents linking into per-file, per-symbol sections.
+
+use std::fmt::Write as _;
+
+use crate::model::{format_generics, FileSymbols, Symbol, SymbolKind};
+use crate::render::slug::Slugger;
+
+/// Render every file's symbols into one Markdown document: a table of
+/// contents followed by one section per file, nested by struct/impl/fn.
+pub fn render_codebook(files: &[FileSymbols]) -> String {
+    let mut slugger = Slugger::new();
+    let mut toc = String::from("# Code Book\n\n## Table of Contents\n\n");
+    let mut body = String::new();
+
+    for file in files {
+        let file_heading = file.path.display().to_string();
+        let file_anchor = slugger.slugify(&file_heading);
+        let _ = writeln!(toc, "- [{file_heading}](#{file_anchor})");
+        let _ = writeln!(body, "## {file_heading}\n");
+
+        for symbol in &file.symbols {
+            render_symbol(symbol, 1, &mut slugger, &mut toc, &mut body);
+        }
+    }
+
+    format!("{toc}\n{body}")
+}
+
+fn render_symbol(
+    symbol: &Symbol,
+    toc_depth: usize,
+    slugger: &mut Slugger,
+    toc: &mut String,
+    body: &mut String,
+) {
+    let heading_level = toc_depth + 2; // file is `##`, so top symbols start at `###`
+    let label = symbol_label(symbol);
+    let anchor = slugger.slugify(&label);
+
+    let indent = "  ".repeat(toc_depth);
+    let _ = writeln!(toc, "{indent}- [{label}](#{anchor})");
+    let _ = writeln!(body, "{} {label}\n", "#".repeat(heading_level));
+    if let Some(doc) = &symbol.doc {
+        let _ = writeln!(body, "{doc}\n");
+    }
+
+    for child in &symbol.children {
+        render_symbol(child, toc_depth + 1, slugger, toc, body);
+    }
+}
+
+fn symbol_label(symbol: &Symbol) -> String {
+    let generics = format_generics(&symbol.generics);
+    match &symbol.kind {
+        SymbolKind::Struct => format!("{}{generics} (struct)", symbol.name),
+        SymbolKind::Function => format!("{}{generics} (fn)", symbol.name),
+        SymbolKind::Impl { target } => format!("impl{generics} {target}"),
+        SymbolKind::Method { has_default } if *has_default => {
+            format!("{}{generics} (fn)", symbol.name)
+        }
+        SymbolKind::Method { .. } => format!("{}{generics} (fn, required)", symbol.name),
+        SymbolKind::Trait => format!("{}{generics} (trait)", symbol.name),
+        SymbolKind::Enum => format!("{}{generics} (enum)", symbol.name),
+        SymbolKind::Variant => format!("{} (variant)", symbol.name),
+        SymbolKind::TypeAlias => format!("{}{generics} (type)", symbol.name),
+        SymbolKind::Const => format!("{} (const)", symbol.name),
+        SymbolKind::Static => format!("{} (static)", symbol.name),
+        SymbolKind::Macro => format!("{}! (macro)", symbol.name),
+    }
+}
@@ -0,0 +1,4 @@
+mod markdown;
+mod slug;
+
+pub use markdown::render_codebook;
@@ -0,0 +1,44 @@
+//! GitHub-style heading anchors, deduplicated across a whole document.
+
+use std::collections::HashMap;
+
+/// Generates unique anchor slugs the same way GitHub's Markdown renderer
+/// does, so table-of-contents links resolve to the matching heading.
+#[derive(Default)]
+pub struct Slugger {
+    seen: HashMap<String, usize>,
+}
+
+impl Slugger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Produce a unique slug for `heading`, suffixing repeats with `-1`,
+    /// `-2`, etc.
+    pub fn slugify(&mut self, heading: &str) -> String {
+        let base: String = heading
+            .chars()
+            .filter_map(|c| {
+                if c.is_alphanumeric() {
+                    Some(c.to_ascii_lowercase())
+                } else if c == '_' {
+                    Some('_')
+                } else if c.is_whitespace() || c == '-' {
+                    Some('-')
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let count = self.seen.entry(base.clone()).or_insert(0);
+        let slug = if *count == 0 {
+            base
+        } else {
+            format!("{base}-{count}")
+        };
+        *count += 1;
+        slug
+    }
+}
@@ -0,0 +1,199 @@
+//! SQLite-backed persistence for extracted symbols, so a large ingested
+//! codebase can be queried without re-parsing its source on every run.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::{Row, SqlitePool};
+
+use crate::model::FileSymbols;
+
+/// One symbol row read back out of storage, joined with its owning file's
+/// path.
+#[derive(Debug, Clone)]
+pub struct StoredSymbol {
+    pub id: i64,
+    pub file_path: String,
+    /// The row id of the enclosing item (e.g. the `impl` a method belongs
+    /// to), or `None` for a top-level item.
+    pub parent_id: Option<i64>,
+    pub name: String,
+    pub kind: String,
+    pub visibility: String,
+    pub signature: String,
+    pub doc: Option<String>,
+    pub start_line: i64,
+    pub end_line: i64,
+}
+
+fn row_to_symbol(row: &sqlx::sqlite::SqliteRow) -> StoredSymbol {
+    StoredSymbol {
+        id: row.get("id"),
+        file_path: row.get("file_path"),
+        parent_id: row.get("parent_id"),
+        name: row.get("name"),
+        kind: row.get("kind"),
+        visibility: row.get("visibility"),
+        signature: row.get("signature"),
+        doc: row.get("doc"),
+        start_line: row.get("start_line"),
+        end_line: row.get("end_line"),
+    }
+}
+
+/// A SQLite database of extracted symbols.
+pub struct Storage {
+    pool: SqlitePool,
+}
+
+impl Storage {
+    /// Open (creating if missing) a SQLite database at `path` and ensure the
+    /// symbol schema exists.
+    pub async fn open(path: &Path) -> Result<Self> {
+        let options = SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true);
+        // A single connection keeps writes serialized (SQLite's usual
+        // mode) and, for `:memory:` databases, ensures every query lands
+        // on the same in-memory instance instead of a fresh empty one.
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .with_context(|| format!("failed to open database at {}", path.display()))?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS files (
+                id INTEGER PRIMARY KEY,
+                path TEXT NOT NULL UNIQUE
+            )",
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS symbols (
+                id INTEGER PRIMARY KEY,
+                file_id INTEGER NOT NULL REFERENCES files(id),
+                parent_id INTEGER REFERENCES symbols(id),
+                name TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                visibility TEXT NOT NULL,
+                signature TEXT NOT NULL,
+                doc TEXT,
+                start_line INTEGER NOT NULL,
+                end_line INTEGER NOT NULL
+            )",
+        )
+        .execute(&pool)
+        .await?;
+
+        Ok(Self { pool })
+    }
+
+    /// Persist every symbol extracted from `file`, replacing whatever was
+    /// previously stored for that path. Re-ingesting a changed file updates
+    /// it in place rather than duplicating rows, and every nested symbol (a
+    /// method or an enum variant) is linked back to its owning `impl`, trait
+    /// or enum via `parent_id`.
+    pub async fn insert_file(&self, file: &FileSymbols) -> Result<()> {
+        let path = file.path.to_string_lossy();
+        let mut tx = self.pool.begin().await?;
+
+        let file_id: i64 = sqlx::query_scalar(
+            "INSERT INTO files (path) VALUES (?1)
+             ON CONFLICT(path) DO UPDATE SET path = excluded.path
+             RETURNING id",
+        )
+        .bind(path.as_ref())
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query("DELETE FROM symbols WHERE file_id = ?1")
+            .bind(file_id)
+            .execute(&mut *tx)
+            .await?;
+
+        // Iterative pre-order walk: avoids recursive `async fn`, which
+        // would otherwise need boxing to be legal.
+        let mut pending: Vec<(Option<i64>, &crate::model::Symbol)> =
+            file.symbols.iter().map(|s| (None, s)).collect();
+        pending.reverse();
+
+        while let Some((parent_id, symbol)) = pending.pop() {
+            let id: i64 = sqlx::query_scalar(
+                "INSERT INTO symbols
+                    (file_id, parent_id, name, kind, visibility, signature, doc, start_line, end_line)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                 RETURNING id",
+            )
+            .bind(file_id)
+            .bind(parent_id)
+            .bind(&symbol.name)
+            .bind(symbol.kind.as_str())
+            .bind(symbol.visibility.as_str())
+            .bind(&symbol.signature)
+            .bind(&symbol.doc)
+            .bind(symbol.line as i64)
+            .bind(symbol.end_line as i64)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            for child in symbol.children.iter().rev() {
+                pending.push((Some(id), child));
+            }
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Every public function or method, across all ingested files.
+    pub async fn public_functions(&self) -> Result<Vec<StoredSymbol>> {
+        self.query_symbols(
+            "SELECT symbols.*, files.path AS file_path FROM symbols
+             JOIN files ON files.id = symbols.file_id
+             WHERE symbols.visibility = 'public'
+               AND symbols.kind IN ('function', 'method')
+             ORDER BY files.path, symbols.start_line",
+            &[],
+        )
+        .await
+    }
+
+    /// Every symbol extracted from `file_path`, in source order.
+    pub async fn symbols_in_file(&self, file_path: &str) -> Result<Vec<StoredSymbol>> {
+        self.query_symbols(
+            "SELECT symbols.*, files.path AS file_path FROM symbols
+             JOIN files ON files.id = symbols.file_id
+             WHERE files.path = ?1
+             ORDER BY symbols.start_line",
+            &[file_path],
+        )
+        .await
+    }
+
+    /// Full-text search over doc comments (case-insensitive substring
+    /// match).
+    pub async fn search_docs(&self, text: &str) -> Result<Vec<StoredSymbol>> {
+        let pattern = format!("%{text}%");
+        self.query_symbols(
+            "SELECT symbols.*, files.path AS file_path FROM symbols
+             JOIN files ON files.id = symbols.file_id
+             WHERE symbols.doc LIKE ?1
+             ORDER BY files.path, symbols.start_line",
+            &[&pattern],
+        )
+        .await
+    }
+
+    async fn query_symbols(&self, sql: &'static str, binds: &[&str]) -> Result<Vec<StoredSymbol>> {
+        let mut query = sqlx::query(sql);
+        for bind in binds {
+            query = query.bind(*bind);
+        }
+        let rows = query.fetch_all(&self.pool).await?;
+        Ok(rows.iter().map(row_to_symbol).collect())
+    }
+}
@@ -0,0 +1,173 @@
+//! Ingests a whole crate or Cargo workspace by shelling out to
+//! `cargo metadata` and following each target's `mod` tree, instead of
+//! operating on a loose collection of files.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use cargo_metadata::{MetadataCommand, TargetKind};
+
+use crate::extractor;
+use crate::model::{FileSymbols, Symbol, SymbolKind};
+
+/// One source file's worth of symbols, attributed to the package and
+/// module path that owns it (e.g. `mycrate::math`).
+#[derive(Debug, Clone)]
+pub struct ModuleSymbols {
+    pub package: String,
+    pub module_path: Vec<String>,
+    pub file: FileSymbols,
+}
+
+impl ModuleSymbols {
+    /// Fully-qualified names for every symbol in this module, e.g.
+    /// `mycrate::math::add` or `mycrate::math::Calculator::add`.
+    pub fn qualified_names(&self) -> Vec<String> {
+        let mut prefix = vec![self.package.clone()];
+        prefix.extend(self.module_path.iter().cloned());
+
+        let mut names = Vec::new();
+        for symbol in &self.file.symbols {
+            collect_qualified(symbol, &prefix, &mut names);
+        }
+        names
+    }
+}
+
+fn collect_qualified(symbol: &Symbol, prefix: &[String], out: &mut Vec<String>) {
+    let mut path = prefix.to_vec();
+    path.push(symbol.name.clone());
+
+    // An impl block's name is its target type's, which is already listed
+    // where the type is defined; only its methods get names of their own.
+    if !matches!(symbol.kind, SymbolKind::Impl { .. }) {
+        out.push(path.join("::"));
+    }
+    for child in &symbol.children {
+        collect_qualified(child, &path, out);
+    }
+}
+
+/// Discover every workspace member via `cargo metadata`, then walk each
+/// library/binary target's module tree - following `mod` declarations and
+/// `#[path]` attributes - to ingest the full crate.
+pub fn ingest_workspace(manifest_path: Option<&Path>) -> Result<Vec<ModuleSymbols>> {
+    let mut cmd = MetadataCommand::new();
+    if let Some(path) = manifest_path {
+        cmd.manifest_path(path);
+    }
+    let metadata = cmd.exec().context("failed to run `cargo metadata`")?;
+
+    let workspace_members: HashSet<_> = metadata.workspace_members.iter().collect();
+
+    let mut modules = Vec::new();
+    for package in &metadata.packages {
+        if !workspace_members.contains(&package.id) {
+            continue;
+        }
+        for target in &package.targets {
+            if !target
+                .kind
+                .iter()
+                .any(|k| matches!(k, TargetKind::Lib | TargetKind::Bin))
+            {
+                continue;
+            }
+            walk_module(
+                &package.name,
+                target.src_path.as_std_path(),
+                Vec::new(),
+                &mut modules,
+            )?;
+        }
+    }
+
+    Ok(modules)
+}
+
+/// Parse `file_path`, record its symbols under `module_path`, then recurse
+/// into every `mod name;` declaration it contains.
+fn walk_module(
+    package: &str,
+    file_path: &Path,
+    module_path: Vec<String>,
+    out: &mut Vec<ModuleSymbols>,
+) -> Result<()> {
+    let source = std::fs::read_to_string(file_path)
+        .with_context(|| format!("failed to read {}", file_path.display()))?;
+    let parsed = syn::parse_file(&source)
+        .with_context(|| format!("failed to parse {}", file_path.display()))?;
+
+    for item in &parsed.items {
+        let syn::Item::Mod(m) = item else { continue };
+        if m.content.is_some() {
+            continue; // inline `mod name { .. }`: no separate file to follow
+        }
+        let child_path = resolve_mod_path(file_path, &m.ident.to_string(), &m.attrs)?;
+        let mut child_module_path = module_path.clone();
+        child_module_path.push(m.ident.to_string());
+        walk_module(package, &child_path, child_module_path, out)?;
+    }
+
+    let file = extractor::extract_file(file_path)?;
+    out.push(ModuleSymbols {
+        package: package.to_string(),
+        module_path,
+        file,
+    });
+    Ok(())
+}
+
+/// Resolve the file a `mod name;` declaration in `parent_file` points at,
+/// honoring an explicit `#[path = "..."]` override and both the flat
+/// (`name.rs`) and nested (`name/mod.rs`) module file conventions.
+fn resolve_mod_path(parent_file: &Path, name: &str, attrs: &[syn::Attribute]) -> Result<PathBuf> {
+    let parent_dir = parent_file.parent().unwrap_or_else(|| Path::new("."));
+
+    if let Some(explicit) = explicit_path_attr(attrs) {
+        return Ok(parent_dir.join(explicit));
+    }
+
+    let is_mod_root = parent_file
+        .file_stem()
+        .is_some_and(|stem| stem == "lib" || stem == "main" || stem == "mod");
+    let search_dir = if is_mod_root {
+        parent_dir.to_path_buf()
+    } else {
+        let own_stem = parent_file.file_stem().unwrap_or_default();
+        parent_dir.join(own_stem)
+    };
+
+    let flat = search_dir.join(format!("{name}.rs"));
+    if flat.exists() {
+        return Ok(flat);
+    }
+    let nested = search_dir.join(name).join("mod.rs");
+    if nested.exists() {
+        return Ok(nested);
+    }
+
+    bail!(
+        "could not find source file for `mod {name};` declared in {}",
+        parent_file.display()
+    )
+}
+
+fn explicit_path_attr(attrs: &[syn::Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| {
+        if !attr.path().is_ident("path") {
+            return None;
+        }
+        let syn::Meta::NameValue(nv) = &attr.meta else {
+            return None;
+        };
+        let syn::Expr::Lit(expr_lit) = &nv.value else {
+            return None;
+        };
+        let syn::Lit::Str(s) = &expr_lit.lit else {
+            return None;
+        };
+        Some(s.value())
+    })
+}
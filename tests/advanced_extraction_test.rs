@@ -0,0 +1,56 @@
+use std::path::Path;
+
+use code_ingest::extractor::extract_file;
+use code_ingest::model::SymbolKind;
+
+#[test]
+fn extracts_traits_enums_generics_and_macros() {
+    let file = extract_file(Path::new("tests/fixtures/sample_code/advanced.rs")).unwrap();
+
+    let describe = file
+        .symbols
+        .iter()
+        .find(|s| s.name == "Describe")
+        .expect("trait Describe");
+    assert_eq!(describe.kind, SymbolKind::Trait);
+    assert_eq!(describe.children.len(), 2);
+    assert_eq!(
+        describe.children[0].kind,
+        SymbolKind::Method { has_default: false }
+    );
+    assert_eq!(
+        describe.children[1].kind,
+        SymbolKind::Method { has_default: true }
+    );
+
+    let shape = file
+        .symbols
+        .iter()
+        .find(|s| s.name == "Shape")
+        .expect("enum Shape");
+    assert_eq!(shape.kind, SymbolKind::Enum);
+    let variant_names: Vec<&str> = shape.children.iter().map(|v| v.name.as_str()).collect();
+    assert_eq!(variant_names, vec!["Circle", "Square"]);
+
+    let wrapper = file
+        .symbols
+        .iter()
+        .find(|s| s.name == "Wrapper")
+        .expect("struct Wrapper");
+    assert_eq!(wrapper.generics.len(), 1);
+    assert_eq!(wrapper.generics[0].name, "T");
+    assert_eq!(wrapper.generics[0].bounds, vec!["Clone"]);
+
+    assert!(file
+        .symbols
+        .iter()
+        .any(|s| s.name == "BoxError" && s.kind == SymbolKind::TypeAlias));
+    assert!(file
+        .symbols
+        .iter()
+        .any(|s| s.name == "ANSWER" && s.kind == SymbolKind::Const));
+    assert!(file
+        .symbols
+        .iter()
+        .any(|s| s.name == "log_it" && s.kind == SymbolKind::Macro));
+}
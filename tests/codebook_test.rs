@@ -0,0 +1,29 @@
+use std::path::Path;
+
+use code_ingest::extractor::extract_file;
+use code_ingest::render::render_codebook;
+
+#[test]
+fn renders_toc_and_sections_for_fixture_files() {
+    let files = vec![
+        extract_file(Path::new("tests/fixtures/sample_code/sample.rs")).unwrap(),
+        extract_file(Path::new("tests/fixtures/sample_code/with_impl.rs")).unwrap(),
+    ];
+
+    let codebook = render_codebook(&files);
+
+    assert!(codebook.contains("# Code Book"));
+    assert!(codebook.contains("## Table of Contents"));
+
+    // Every file gets its own section, linked from the TOC.
+    assert!(codebook.contains("## tests/fixtures/sample_code/sample.rs"));
+    assert!(codebook.contains("## tests/fixtures/sample_code/with_impl.rs"));
+
+    // Struct, impl, and nested method sections are all present.
+    assert!(codebook.contains("### Calculator (struct)"));
+    assert!(codebook.contains("### impl Calculator"));
+    assert!(codebook.contains("#### multiply (fn)"));
+
+    // Doc comments are carried through into the rendered section.
+    assert!(codebook.contains("Add two numbers"));
+}
@@ -0,0 +1,43 @@
+// Rust file exercising traits, enums, generics, and macros
+
+/// Things that can be described.
+pub trait Describe {
+    /// Required: every implementor must supply a description.
+    fn describe(&self) -> String;
+
+    /// Provided: implementors may override this, but don't have to.
+    fn describe_loudly(&self) -> String {
+        self.describe().to_uppercase()
+    }
+}
+
+/// A shape, either round or with straight sides.
+pub enum Shape {
+    Circle,
+    Square,
+}
+
+/// Wraps a value of any `Clone` type.
+pub struct Wrapper<T: Clone> {
+    value: T,
+}
+
+impl<T: Clone> Wrapper<T> {
+    /// Clone the wrapped value back out.
+    pub fn get(&self) -> T {
+        self.value.clone()
+    }
+}
+
+/// An alias for a boxed error.
+pub type BoxError = Box<dyn std::error::Error>;
+
+/// The answer.
+pub const ANSWER: i32 = 42;
+
+/// A logging macro.
+macro_rules! log_it {
+    ($msg:expr) => {
+        println!("{}", $msg);
+    };
+}
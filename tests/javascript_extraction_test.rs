@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use code_ingest::extractor::ExtractorRegistry;
+use code_ingest::model::SymbolKind;
+
+#[test]
+fn extracts_classes_functions_and_methods_from_javascript() {
+    let registry = ExtractorRegistry::with_defaults();
+    let file = registry
+        .extract_file(Path::new("tests/fixtures/sample_code/sample.js"))
+        .unwrap();
+
+    let greet = file
+        .symbols
+        .iter()
+        .find(|s| s.name == "greet")
+        .expect("function greet");
+    assert_eq!(greet.kind, SymbolKind::Function);
+    assert_eq!(greet.doc.as_deref(), Some("Greet a person."));
+
+    let calculator = file
+        .symbols
+        .iter()
+        .find(|s| s.name == "Calculator")
+        .expect("class Calculator");
+    assert_eq!(calculator.kind, SymbolKind::Struct);
+    assert_eq!(calculator.doc.as_deref(), Some("A simple calculator."));
+
+    let method_names: Vec<&str> = calculator
+        .children
+        .iter()
+        .map(|m| m.name.as_str())
+        .collect();
+    assert_eq!(method_names, vec!["constructor", "add", "multiply"]);
+
+    let add = calculator
+        .children
+        .iter()
+        .find(|m| m.name == "add")
+        .unwrap();
+    assert_eq!(add.doc.as_deref(), Some("Add two numbers."));
+
+    let multiply = calculator
+        .children
+        .iter()
+        .find(|m| m.name == "multiply")
+        .unwrap();
+    assert_eq!(multiply.doc.as_deref(), Some("Multiply two numbers"));
+}
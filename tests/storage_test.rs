@@ -0,0 +1,42 @@
+use std::path::Path;
+
+use code_ingest::extractor::extract_file;
+use code_ingest::storage::Storage;
+
+#[tokio::test]
+async fn stores_and_queries_symbols() {
+    let storage = Storage::open(Path::new(":memory:")).await.unwrap();
+
+    let sample = extract_file(Path::new("tests/fixtures/sample_code/sample.rs")).unwrap();
+    let with_impl = extract_file(Path::new("tests/fixtures/sample_code/with_impl.rs")).unwrap();
+    storage.insert_file(&sample).await.unwrap();
+    storage.insert_file(&with_impl).await.unwrap();
+
+    let public_fns = storage.public_functions().await.unwrap();
+    let names: Vec<&str> = public_fns.iter().map(|s| s.name.as_str()).collect();
+    assert!(names.contains(&"add"));
+    assert!(names.contains(&"multiply"));
+    assert!(!names.contains(&"greet")); // private, excluded
+
+    // The `multiply` method is linked back to its `Calculator` impl parent.
+    let multiply = public_fns.iter().find(|s| s.name == "multiply").unwrap();
+    let impl_block = storage
+        .symbols_in_file("tests/fixtures/sample_code/with_impl.rs")
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|s| s.kind == "impl")
+        .unwrap();
+    assert_eq!(multiply.parent_id, Some(impl_block.id));
+
+    let found = storage.search_docs("Add two numbers").await.unwrap();
+    assert_eq!(found.len(), 2);
+
+    // Re-ingesting the same file replaces rather than duplicates its rows.
+    storage.insert_file(&sample).await.unwrap();
+    let in_sample = storage
+        .symbols_in_file("tests/fixtures/sample_code/sample.rs")
+        .await
+        .unwrap();
+    assert_eq!(in_sample.len(), sample.symbols.len());
+}
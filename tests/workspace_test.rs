@@ -0,0 +1,17 @@
+use code_ingest::workspace::ingest_workspace;
+
+#[test]
+fn attributes_symbols_to_package_and_module_path() {
+    let modules = ingest_workspace(None).expect("cargo metadata ingestion should succeed");
+
+    let names: Vec<String> = modules.iter().flat_map(|m| m.qualified_names()).collect();
+
+    // A free function nested under `extractor::rust` gets a fully-qualified name.
+    assert!(names
+        .iter()
+        .any(|n| n == "code-ingest::extractor::rust::extract_file"));
+    // A method nested under a struct's impl block includes the struct name.
+    assert!(names
+        .iter()
+        .any(|n| n == "code-ingest::render::slug::Slugger::slugify"));
+}